@@ -0,0 +1,44 @@
+//! encoder defines the pluggable phonetic-matching subsystem: a common
+//! `PhoneticEncoder` trait plus the selectable algorithm implementations.
+
+use crate::Soundex;
+
+/// PhoneticEncoder is implemented by every phonetic-matching algorithm in
+/// this crate, so callers can compare names under whichever one they choose.
+pub trait PhoneticEncoder {
+    /// encode computes the phonetic key for `s` under this algorithm.
+    fn encode(&self, s: &str) -> String;
+}
+
+/// SoundexEncoder wraps the crate's classic Soundex implementation,
+/// producing the same key as `soundex()`: 4 characters by default, or the
+/// complete value with the `full` feature.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SoundexEncoder;
+
+impl PhoneticEncoder for SoundexEncoder {
+    fn encode(&self, s: &str) -> String {
+        s.soundex()
+    }
+}
+
+/// NysiisEncoder computes the New York State Identification and
+/// Intelligence System key.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NysiisEncoder;
+
+impl PhoneticEncoder for NysiisEncoder {
+    fn encode(&self, s: &str) -> String {
+        crate::nysiis::nysiis(s)
+    }
+}
+
+/// MetaphoneEncoder computes the Metaphone key.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetaphoneEncoder;
+
+impl PhoneticEncoder for MetaphoneEncoder {
+    fn encode(&self, s: &str) -> String {
+        crate::metaphone::metaphone(s)
+    }
+}
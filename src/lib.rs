@@ -1,4 +1,6 @@
-//! soundex_rs is a library that calculates the words' soundex.
+//! soundex_rs is a library that calculates the words' soundex, and more
+//! generally exposes a small phonetic-matching subsystem with a handful of
+//! selectable algorithms.
 //!
 //! # References
 //! <https://support.esri.com/en/technical-article/000003773>
@@ -8,15 +10,33 @@
 //! | --------| -------------|
 //! | default | The result retains the first four characters of the soundex value｜
 //! | full    | The result retains the complete value of soundex |
+//! | unicode | Adds `soundex_normalized`, which runs NFKD normalization before encoding |
 //!
 //! # Examples
 //! ```
 //! use soundex_rs::Soundex;
 //! println!("{}", "hello world".soundex());
 //! ```
+//!
+//! To compare names under a different algorithm, use [`equal_with`] with one
+//! of the [`PhoneticEncoder`] implementations ([`SoundexEncoder`],
+//! [`NysiisEncoder`], [`MetaphoneEncoder`]):
+//! ```
+//! use soundex_rs::{equal_with, NysiisEncoder};
+//! assert!(equal_with("Robert", "ROBERT", &NysiisEncoder));
+//! ```
 
 use std::ops::Deref;
 
+#[cfg(feature = "unicode")]
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+mod encoder;
+mod metaphone;
+mod nysiis;
+
+pub use encoder::{MetaphoneEncoder, NysiisEncoder, PhoneticEncoder, SoundexEncoder};
+
 pub trait Soundex: Deref<Target = str> {
     /// soundex get the string's soundex value.
     /// # Examples
@@ -29,51 +49,213 @@ pub trait Soundex: Deref<Target = str> {
     /// }
     /// ```
     fn soundex(&self) -> String;
+
+    /// soundex_normalized first expands "ß"/"ẞ" to "ss" and folds the input
+    /// through Unicode NFKD decomposition, stripping combining marks, so
+    /// accented and compatibility characters (e.g. "Müller", "Gauß") code
+    /// the same as their ASCII spelling, before running the usual soundex
+    /// algorithm. Requires the `unicode` feature.
+    /// # Examples
+    /// ```
+    /// use soundex_rs::Soundex;
+    /// assert_eq!("Müller".soundex_normalized(), "Muller".soundex());
+    /// assert_eq!("Straße".soundex_normalized(), "Strasse".soundex());
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn soundex_normalized(&self) -> String;
+
+    /// soundex_american implements the standard American Soundex rule for
+    /// vowels and H/W: a vowel between two consonants that share the same
+    /// code forces the code to be emitted twice, while an H or W between
+    /// them does not (H/W are transparent and neither reset the running
+    /// code nor count as a vowel). This differs from the default `soundex`,
+    /// which drops a repeated code regardless of what separates it.
+    /// # Examples
+    /// ```
+    /// use soundex_rs::Soundex;
+    /// if cfg!(feature="full") {
+    ///     assert_eq!("Ashcraft".soundex_american(), "A2613".to_string());
+    /// } else {
+    ///     assert_eq!("Ashcraft".soundex_american(), "A261".to_string());
+    /// }
+    /// ```
+    fn soundex_american(&self) -> String;
+
+    /// soundex_with_len computes the soundex value truncated/padded to
+    /// exactly `len` characters, or the complete, untruncated value when
+    /// `len` is `None`. This makes the choice between the default 4-char
+    /// key and the `full` key a runtime decision instead of a compile-time
+    /// one, so callers can index at whatever length they need.
+    /// # Examples
+    /// ```
+    /// use soundex_rs::Soundex;
+    /// assert_eq!("hello world".soundex_with_len(Some(4)), "H464".to_string());
+    /// assert_eq!("hello world".soundex_with_len(Some(6)), "H46430".to_string());
+    /// assert_eq!("hello world".soundex_with_len(None), "H4643".to_string());
+    /// ```
+    fn soundex_with_len(&self, len: Option<usize>) -> String;
 }
 
 /// Default implementation for strings.
 impl<T: Deref<Target = str>> Soundex for T {
     fn soundex(&self) -> String {
+        self.soundex_with_len(if cfg!(feature = "full") { None } else { Some(4) })
+    }
+
+    #[cfg(feature = "unicode")]
+    fn soundex_normalized(&self) -> String {
+        if self.is_empty() {
+            return Default::default();
+        }
+
+        let sharp_s_expanded: String = self
+            .chars()
+            .flat_map(|c| match c {
+                'ß' | 'ẞ' => ['s', 's'].to_vec(),
+                c => vec![c],
+            })
+            .collect();
+        let folded: String = sharp_s_expanded
+            .nfkd()
+            .filter(|c| !is_combining_mark(*c))
+            .collect();
+        encode_chars(
+            folded.chars(),
+            if cfg!(feature = "full") { None } else { Some(4) },
+        )
+    }
+
+    fn soundex_american(&self) -> String {
         if self.is_empty() {
             return Default::default();
         }
 
-        let mut r = Vec::with_capacity(4);
-        let mut last = None;
-        let mut count = 0;
+        encode_chars_american(
+            self.chars(),
+            if cfg!(feature = "full") { None } else { Some(4) },
+        )
+    }
+
+    fn soundex_with_len(&self, len: Option<usize>) -> String {
+        if self.is_empty() {
+            return Default::default();
+        }
 
-        for next in self.chars() {
-            let score = number_map(next);
+        encode_chars(self.chars(), len)
+    }
+}
 
-            if last.is_none() {
-                if !next.is_alphanumeric() {
-                    continue;
-                }
+/// encode_chars runs the classic soundex algorithm over a char sequence,
+/// truncating/padding to `len` characters, or keeping the complete value
+/// when `len` is `None` (still padded with '0' to at least 4 characters).
+#[inline(always)]
+fn encode_chars(chars: impl Iterator<Item = char>, len: Option<usize>) -> String {
+    if len == Some(0) {
+        return String::new();
+    }
 
-                last = score;
-                r.push(next.to_ascii_uppercase());
-            } else {
-                if !next.is_ascii_alphabetic() || is_drop(next) || score == last {
-                    continue;
-                }
+    let mut r = Vec::with_capacity(len.unwrap_or(4));
+    let mut last = None;
+    let mut count = 0;
 
-                last = score;
-                r.push(score.unwrap());
+    for next in chars {
+        let score = number_map(next);
+
+        if last.is_none() {
+            if !next.is_alphanumeric() {
+                continue;
             }
 
-            count += 1;
+            last = score;
+            r.push(next.to_ascii_uppercase());
+        } else {
+            if !next.is_ascii_alphabetic() || is_drop(next) || score == last {
+                continue;
+            }
 
-            if !cfg!(feature = "full") && count == 4 {
+            last = score;
+            r.push(score.unwrap());
+        }
+
+        count += 1;
+
+        if let Some(n) = len {
+            if count == n {
                 break;
             }
         }
+    }
+
+    let min_len = len.unwrap_or(4);
+    if count < min_len {
+        r.extend(vec!['0'; min_len - count])
+    }
+
+    r.into_iter().collect()
+}
+
+/// encode_chars_american runs the classic soundex algorithm, but applies the
+/// standard American Soundex vowel/H-W separator rule instead of the simple
+/// "drop if the code repeats" rule used by `encode_chars`, truncating/padding
+/// to `len` characters as `encode_chars` does.
+#[inline(always)]
+fn encode_chars_american(chars: impl Iterator<Item = char>, len: Option<usize>) -> String {
+    if len == Some(0) {
+        return String::new();
+    }
 
-        if count < 4 {
-            r.extend(vec!['0'; 4 - count])
+    let mut r = Vec::with_capacity(len.unwrap_or(4));
+    let mut last_code = None;
+    let mut had_vowel = false;
+    let mut count = 0;
+
+    for next in chars {
+        if last_code.is_none() && r.is_empty() {
+            if !next.is_alphanumeric() {
+                continue;
+            }
+
+            r.push(next.to_ascii_uppercase());
+            last_code = number_map(next);
+            count += 1;
+            continue;
+        }
+
+        if !next.is_ascii_alphabetic() {
+            continue;
+        }
+
+        match next.to_ascii_lowercase() {
+            'h' | 'w' => continue,
+            'a' | 'e' | 'i' | 'o' | 'u' | 'y' => {
+                had_vowel = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let code = number_map(next);
+        if code != Some('0') && (code != last_code || had_vowel) {
+            r.push(code.unwrap());
+            count += 1;
+        }
+
+        had_vowel = false;
+        last_code = code;
+
+        if let Some(n) = len {
+            if count == n {
+                break;
+            }
         }
+    }
 
-        r.into_iter().collect()
+    let min_len = len.unwrap_or(4);
+    if count < min_len {
+        r.extend(vec!['0'; min_len - count])
     }
+
+    r.into_iter().collect()
 }
 
 #[inline(always)]
@@ -108,7 +290,19 @@ where
     LEFT: Soundex,
     RIGHT: Soundex,
 {
-    left.soundex() == right.soundex()
+    equal_with(left.deref(), right.deref(), &SoundexEncoder)
+}
+
+/// equal_with compares two strings under the given `PhoneticEncoder`,
+/// returning true if the result is equal. Use this instead of `equal` to
+/// compare names under NYSIIS, Metaphone, or any other implementation.
+/// # Examples
+/// ```
+/// use soundex_rs::{equal_with, NysiisEncoder};
+/// assert!(equal_with("Robert", "ROBERT", &NysiisEncoder));
+/// ```
+pub fn equal_with<E: PhoneticEncoder>(left: &str, right: &str, encoder: &E) -> bool {
+    encoder.encode(left) == encoder.encode(right)
 }
 
 #[cfg(test)]
@@ -160,10 +354,70 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn test_soundex_normalized() {
+        let m = vec![
+            ("Müller", "Muller"),
+            ("Gauß", "Gauss"),
+            ("café", "cafe"),
+            ("Maßen", "Massen"),
+            ("Straße", "Strasse"),
+            ("Weiß", "Weiss"),
+        ];
+
+        for (accented, ascii) in m.into_iter() {
+            assert_eq!(accented.soundex_normalized(), ascii.soundex(), "{}", accented);
+        }
+    }
+
+    #[test]
+    fn test_soundex_american() {
+        let m = vec![
+            ("Ashcraft", "A2613".to_string()),
+            ("Pfister", "P236".to_string()),
+            ("Tymczak", "T522".to_string()),
+            ("", "".to_string()),
+        ];
+
+        for (i, v) in m.into_iter() {
+            if cfg!(feature = "full") {
+                assert_eq!(i.soundex_american(), v, "{}", i);
+            } else {
+                assert_eq!(
+                    i.soundex_american(),
+                    String::from_iter(v.chars().take(4)),
+                    "{}",
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_soundex_with_len() {
+        assert_eq!("hello world".soundex_with_len(Some(4)), "H464".to_string());
+        assert_eq!("hello world".soundex_with_len(Some(6)), "H46430".to_string());
+        assert_eq!("hello world".soundex_with_len(None), "H4643".to_string());
+        assert_eq!("x".soundex_with_len(Some(4)), "X000".to_string());
+        assert_eq!("".soundex_with_len(Some(4)), "".to_string());
+        assert_eq!("hello world".soundex_with_len(Some(0)), "".to_string());
+    }
+
     #[test]
     fn test_equal() {
         assert!(equal("hello", "hello".to_string()));
         assert!(equal("hello", "hello"));
         assert!(!equal("hello world", "hello".to_string()));
     }
+
+    #[test]
+    fn test_equal_with() {
+        use crate::{equal_with, MetaphoneEncoder, NysiisEncoder, SoundexEncoder};
+
+        assert!(equal_with("hello", "hello", &SoundexEncoder));
+        assert!(equal_with("Robert", "ROBERT", &NysiisEncoder));
+        assert!(equal_with("Knight", "Knight", &MetaphoneEncoder));
+        assert!(!equal_with("Robert", "Rupert", &NysiisEncoder));
+    }
 }
@@ -0,0 +1,205 @@
+//! metaphone implements a simplified version of Lawrence Philips' Metaphone
+//! phonetic algorithm.
+
+#[inline(always)]
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+/// strip_initial_silent_letters handles the classic Metaphone initial-cluster
+/// exceptions: it drops the leading letter of AE, GN, KN, PN and WR, reduces
+/// an initial WH to W, and turns an initial X into S.
+fn strip_initial_silent_letters(chars: Vec<char>) -> Vec<char> {
+    let s: String = chars.iter().collect();
+
+    if s.starts_with("AE")
+        || s.starts_with("GN")
+        || s.starts_with("KN")
+        || s.starts_with("PN")
+        || s.starts_with("WR")
+    {
+        return chars[1..].to_vec();
+    }
+
+    if s.starts_with("WH") {
+        let mut r = vec!['W'];
+        r.extend_from_slice(&chars[2..]);
+        return r;
+    }
+
+    if s.starts_with('X') {
+        let mut r = vec!['S'];
+        r.extend_from_slice(&chars[1..]);
+        return r;
+    }
+
+    chars
+}
+
+/// metaphone computes the Metaphone phonetic key for `s`.
+pub fn metaphone(s: &str) -> String {
+    let chars: Vec<char> = s
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    if chars.is_empty() {
+        return String::new();
+    }
+
+    let chars = strip_initial_silent_letters(chars);
+    if chars.is_empty() {
+        return String::new();
+    }
+
+    let mut key = String::new();
+    let mut last: Option<char> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let prev = if i == 0 { None } else { Some(chars[i - 1]) };
+        let next = chars.get(i + 1).copied();
+        let next2 = chars.get(i + 2).copied();
+
+        if is_vowel(c) {
+            if i == 0 {
+                push(&mut key, &mut last, c);
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            'B' => {
+                if !(next.is_none() && prev == Some('M')) {
+                    push(&mut key, &mut last, 'B');
+                }
+            }
+            'C' => {
+                if next == Some('I') && next2 == Some('A') {
+                    push(&mut key, &mut last, 'X');
+                } else if next == Some('H') {
+                    push(&mut key, &mut last, if prev == Some('S') { 'K' } else { 'X' });
+                    i += 1;
+                } else if matches!(next, Some('I') | Some('E') | Some('Y')) {
+                    push(&mut key, &mut last, 'S');
+                } else {
+                    push(&mut key, &mut last, 'K');
+                }
+            }
+            'D' => {
+                if next == Some('G') && matches!(next2, Some('E') | Some('Y') | Some('I')) {
+                    push(&mut key, &mut last, 'J');
+                    i += 1;
+                } else {
+                    push(&mut key, &mut last, 'T');
+                }
+            }
+            'G' => {
+                if next == Some('H') {
+                    if next2.is_some_and(is_vowel) {
+                        push(&mut key, &mut last, 'F');
+                    }
+                    i += 1;
+                } else if next == Some('N') {
+                    // silent, e.g. "sign"
+                } else if matches!(next, Some('I') | Some('E') | Some('Y')) {
+                    push(&mut key, &mut last, 'J');
+                } else {
+                    push(&mut key, &mut last, 'K');
+                }
+            }
+            'H' => {
+                if prev.is_some_and(is_vowel) && next.is_some_and(is_vowel) {
+                    push(&mut key, &mut last, 'H');
+                }
+            }
+            'K' => {
+                if prev != Some('C') {
+                    push(&mut key, &mut last, 'K');
+                }
+            }
+            'P' => {
+                if next == Some('H') {
+                    push(&mut key, &mut last, 'F');
+                    i += 1;
+                } else {
+                    push(&mut key, &mut last, 'P');
+                }
+            }
+            'Q' => push(&mut key, &mut last, 'K'),
+            'S' => {
+                if next == Some('H') {
+                    push(&mut key, &mut last, 'X');
+                    i += 1;
+                } else if next == Some('I') && matches!(next2, Some('O') | Some('A')) {
+                    push(&mut key, &mut last, 'X');
+                } else {
+                    push(&mut key, &mut last, 'S');
+                }
+            }
+            'T' => {
+                if next == Some('H') {
+                    push(&mut key, &mut last, '0');
+                    i += 1;
+                } else if next == Some('I') && matches!(next2, Some('O') | Some('A')) {
+                    push(&mut key, &mut last, 'X');
+                } else {
+                    push(&mut key, &mut last, 'T');
+                }
+            }
+            'V' => push(&mut key, &mut last, 'F'),
+            'W' => {
+                if next.is_some_and(is_vowel) {
+                    push(&mut key, &mut last, 'W');
+                }
+            }
+            'X' => {
+                push(&mut key, &mut last, 'K');
+                push(&mut key, &mut last, 'S');
+            }
+            'Y' => {
+                if next.is_some_and(is_vowel) {
+                    push(&mut key, &mut last, 'Y');
+                }
+            }
+            'Z' => push(&mut key, &mut last, 'S'),
+            other => push(&mut key, &mut last, other),
+        }
+
+        i += 1;
+    }
+
+    key
+}
+
+/// push appends `c` to `key` unless it repeats the previously emitted code.
+#[inline(always)]
+fn push(key: &mut String, last: &mut Option<char>, c: char) {
+    if *last != Some(c) {
+        key.push(c);
+        *last = Some(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::metaphone;
+
+    #[test]
+    fn test_metaphone() {
+        let m = vec![
+            ("", ""),
+            ("Night", "NT"),
+            ("Charlie", "XRL"),
+            ("Philosophy", "FLSF"),
+            ("Knight", "NT"),
+        ];
+
+        for (i, v) in m.into_iter() {
+            assert_eq!(metaphone(i), v, "{}", i);
+        }
+    }
+}
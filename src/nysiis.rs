@@ -0,0 +1,170 @@
+//! nysiis implements the New York State Identification and Intelligence
+//! System phonetic algorithm.
+//!
+//! # References
+//! <https://support.esri.com/en/technical-article/000003773>
+
+#[inline(always)]
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+/// transform_leading applies the NYSIIS leading-cluster substitutions.
+fn transform_leading(chars: &[char]) -> Vec<char> {
+    let s: String = chars.iter().collect();
+
+    if s.starts_with("MAC") {
+        let mut r = vec!['M', 'C', 'C'];
+        r.extend_from_slice(&chars[3..]);
+        return r;
+    }
+
+    if s.starts_with("KN") {
+        let mut r = vec!['N'];
+        r.extend_from_slice(&chars[2..]);
+        return r;
+    }
+
+    if s.starts_with("PH") || s.starts_with("PF") {
+        let mut r = vec!['F', 'F'];
+        r.extend_from_slice(&chars[2..]);
+        return r;
+    }
+
+    if s.starts_with("SCH") {
+        let mut r = vec!['S', 'S', 'S'];
+        r.extend_from_slice(&chars[3..]);
+        return r;
+    }
+
+    if s.starts_with('K') {
+        let mut r = vec!['C'];
+        r.extend_from_slice(&chars[1..]);
+        return r;
+    }
+
+    chars.to_vec()
+}
+
+/// transform_trailing applies the NYSIIS trailing-cluster substitutions.
+fn transform_trailing(chars: &[char]) -> Vec<char> {
+    let s: String = chars.iter().collect();
+
+    if s.ends_with("EE") || s.ends_with("IE") {
+        let mut r = chars[..chars.len() - 2].to_vec();
+        r.push('Y');
+        return r;
+    }
+
+    for suffix in ["DT", "RT", "RD", "NT", "ND"] {
+        if s.ends_with(suffix) {
+            let mut r = chars[..chars.len() - 2].to_vec();
+            r.push('D');
+            return r;
+        }
+    }
+
+    chars.to_vec()
+}
+
+/// nysiis computes the NYSIIS phonetic key for `s`.
+pub fn nysiis(s: &str) -> String {
+    let chars: Vec<char> = s
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    if chars.is_empty() {
+        return String::new();
+    }
+
+    let chars = transform_leading(&chars);
+    let chars = transform_trailing(&chars);
+
+    let mut key = String::new();
+    key.push(chars[0]);
+    let mut last_key_char = chars[0];
+
+    let mut i = 1;
+    while i < chars.len() {
+        let c = chars[i];
+
+        let code = if is_vowel(c) {
+            while i + 1 < chars.len() && is_vowel(chars[i + 1]) {
+                i += 1;
+            }
+            'A'
+        } else if c == 'Q' {
+            'G'
+        } else if c == 'Z' {
+            'S'
+        } else if c == 'M' {
+            'N'
+        } else if c == 'K' {
+            if i + 1 < chars.len() && chars[i + 1] == 'N' {
+                i += 1;
+            }
+            'C'
+        } else if c == 'S' && chars.get(i + 1) == Some(&'C') && chars.get(i + 2) == Some(&'H') {
+            i += 2;
+            'S'
+        } else if c == 'P' && chars.get(i + 1) == Some(&'H') {
+            i += 1;
+            'F'
+        } else if c == 'H' {
+            let next_is_vowel = chars.get(i + 1).is_some_and(|&n| is_vowel(n));
+            if !is_vowel(last_key_char) || !next_is_vowel {
+                last_key_char
+            } else {
+                'H'
+            }
+        } else if c == 'W' {
+            if is_vowel(last_key_char) {
+                last_key_char
+            } else {
+                'W'
+            }
+        } else {
+            c
+        };
+
+        if code != last_key_char {
+            key.push(code);
+            last_key_char = code;
+        }
+
+        i += 1;
+    }
+
+    if key.ends_with('S') {
+        key.pop();
+    }
+
+    if key.ends_with("AY") {
+        key.remove(key.len() - 2);
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nysiis;
+
+    #[test]
+    fn test_nysiis() {
+        let m = vec![
+            ("", ""),
+            ("Robert", "RABAD"),
+            ("Rupert", "RAPAD"),
+            ("Knuth", "NAT"),
+            ("Schmidt", "SNAD"),
+            ("Mackenzie", "MCANSY"),
+        ];
+
+        for (i, v) in m.into_iter() {
+            assert_eq!(nysiis(i), v, "{}", i);
+        }
+    }
+}